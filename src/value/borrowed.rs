@@ -7,10 +7,16 @@
 //! For simple values, the values are inlined (see [`BorrowedValue`]), but for things that are
 //! backed by octets or string data, `&[u8]` and `&str` are used.
 
+use super::owned::{
+    OwnedElement, OwnedImportSource, OwnedSequence, OwnedStruct, OwnedSymbolToken, OwnedValue,
+};
 use super::{Element, ImportSource, Sequence, Struct, SymbolToken};
-use crate::types::SymbolId;
+use crate::types::{Decimal, SymbolId, Timestamp};
 use crate::value::AnyInt;
 use crate::IonType;
+use smallvec::SmallVec;
+use std::cmp::Ordering;
+use std::collections::HashMap;
 
 /// A borrowed implementation of [`ImportSource`].
 #[derive(Debug, Copy, Clone)]
@@ -23,6 +29,16 @@ impl<'val> BorrowedImportSource<'val> {
     pub fn new(table: &'val str, sid: SymbolId) -> Self {
         Self { table, sid }
     }
+
+    /// Constructs an [`OwnedImportSource`] that owns a copy of this reference's data.
+    pub fn into_owned(self) -> OwnedImportSource {
+        OwnedImportSource::new(self.table, self.sid)
+    }
+
+    /// Constructs an [`OwnedImportSource`] that owns a copy of this reference's data.
+    pub fn to_owned(&self) -> OwnedImportSource {
+        OwnedImportSource::new(self.table, self.sid)
+    }
 }
 
 impl<'val> ImportSource for BorrowedImportSource<'val> {
@@ -55,6 +71,24 @@ impl<'val> BorrowedSymbolToken<'val> {
             source,
         }
     }
+
+    /// Constructs an [`OwnedSymbolToken`] from this, detaching it from the `'val` borrow.
+    pub fn into_owned(self) -> OwnedSymbolToken {
+        OwnedSymbolToken::new(
+            self.text.map(|text| text.to_string()),
+            self.local_sid,
+            self.source.map(BorrowedImportSource::into_owned),
+        )
+    }
+
+    /// Constructs an [`OwnedSymbolToken`] that owns a copy of this reference's data.
+    pub fn to_owned(&self) -> OwnedSymbolToken {
+        OwnedSymbolToken::new(
+            self.text.map(|text| text.to_string()),
+            self.local_sid,
+            self.source.map(|source| source.to_owned()),
+        )
+    }
 }
 
 impl<'val> From<&'val str> for BorrowedSymbolToken<'val> {
@@ -79,6 +113,30 @@ impl<'val> SymbolToken for BorrowedSymbolToken<'val> {
     }
 }
 
+/// Ion symbol equivalence is defined over resolved text: two symbols with the same text are
+/// equal no matter which local SID or import source produced them on the wire. A symbol with no
+/// resolved text compares equal only to another symbol that also has no text (`local_sid` and
+/// `source` are ignored either way), since there's nothing to compare them by.
+impl<'val> PartialEq for BorrowedSymbolToken<'val> {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl<'val> Eq for BorrowedSymbolToken<'val> {}
+
+impl<'val> PartialOrd for BorrowedSymbolToken<'val> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'val> Ord for BorrowedSymbolToken<'val> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.text.cmp(&other.text)
+    }
+}
+
 /// A borrowed implementation of [`Sequence`].
 #[derive(Debug, Clone)]
 pub struct BorrowedSequence<'val> {
@@ -89,6 +147,21 @@ impl<'val> BorrowedSequence<'val> {
     pub fn new(children: Vec<BorrowedElement<'val>>) -> Self {
         Self { children }
     }
+
+    /// Constructs an [`OwnedSequence`] from this, detaching it from the `'val` borrow.
+    pub fn into_owned(self) -> OwnedSequence {
+        OwnedSequence::new(
+            self.children
+                .into_iter()
+                .map(BorrowedElement::into_owned)
+                .collect(),
+        )
+    }
+
+    /// Constructs an [`OwnedSequence`] that owns a copy of this reference's data.
+    pub fn to_owned(&self) -> OwnedSequence {
+        OwnedSequence::new(self.children.iter().map(BorrowedElement::to_owned).collect())
+    }
 }
 
 impl<'val> Sequence for BorrowedSequence<'val> {
@@ -99,17 +172,89 @@ impl<'val> Sequence for BorrowedSequence<'val> {
     }
 }
 
+/// Sequences (lists and s-expressions) compare element-wise, in order.
+impl<'val> PartialEq for BorrowedSequence<'val> {
+    fn eq(&self, other: &Self) -> bool {
+        self.children == other.children
+    }
+}
+
+impl<'val> Eq for BorrowedSequence<'val> {}
+
+impl<'val> PartialOrd for BorrowedSequence<'val> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'val> Ord for BorrowedSequence<'val> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.children.cmp(&other.children)
+    }
+}
+
 /// A borrowed implementation of [`Struct`]
 #[derive(Debug, Clone)]
 pub struct BorrowedStruct<'val> {
-    // TODO model actual map indexing for the struct (maybe as a variant type)
-    //      otherwise struct field lookup will be O(N)
     fields: Vec<(BorrowedSymbolToken<'val>, BorrowedElement<'val>)>,
+    // Maps a field name's text to the indexes into `fields` that use it, in document order.
+    // Ion structs permit repeated field names, hence the multimap. Field names with no text
+    // (a bare local SID or import source with no resolved text) have no entry here and are
+    // only reachable through `iter()`.
+    text_index: HashMap<&'val str, SmallVec<[usize; 1]>>,
 }
 
 impl<'val> BorrowedStruct<'val> {
     pub fn new(fields: Vec<(BorrowedSymbolToken<'val>, BorrowedElement<'val>)>) -> Self {
-        Self { fields }
+        let mut text_index: HashMap<&'val str, SmallVec<[usize; 1]>> = HashMap::new();
+        for (index, (name, _)) in fields.iter().enumerate() {
+            // Accessed as a field (rather than through `SymbolToken::text()`) so the borrow
+            // we index on is `&'val str`, not one scoped to this loop.
+            if let Some(text) = name.text {
+                text_index.entry(text).or_default().push(index);
+            }
+        }
+        Self { fields, text_index }
+    }
+
+    /// Returns the value of the last field with the given name, or `None` if there is no such
+    /// field. Ion structs may have more than one field with the same name; when they do, the
+    /// last one to appear wins, matching how most Ion implementations resolve a single-valued
+    /// field lookup.
+    pub fn get(&self, field: &str) -> Option<&BorrowedElement<'val>> {
+        self.text_index
+            .get(field)
+            .and_then(|indexes| indexes.last())
+            .map(|&index| &self.fields[index].1)
+    }
+
+    /// Returns all values of fields with the given name, in document order.
+    pub fn get_all<'a>(&'a self, field: &str) -> impl Iterator<Item = &'a BorrowedElement<'val>> {
+        self.text_index
+            .get(field)
+            .into_iter()
+            .flatten()
+            .map(move |&index| &self.fields[index].1)
+    }
+
+    /// Constructs an [`OwnedStruct`] from this, detaching it from the `'val` borrow.
+    pub fn into_owned(self) -> OwnedStruct {
+        OwnedStruct::new(
+            self.fields
+                .into_iter()
+                .map(|(name, value)| (name.into_owned(), value.into_owned()))
+                .collect(),
+        )
+    }
+
+    /// Constructs an [`OwnedStruct`] that owns a copy of this reference's data.
+    pub fn to_owned(&self) -> OwnedStruct {
+        OwnedStruct::new(
+            self.fields
+                .iter()
+                .map(|(name, value)| (name.to_owned(), value.to_owned()))
+                .collect(),
+        )
     }
 }
 
@@ -127,19 +272,204 @@ impl<'val> Struct for BorrowedStruct<'val> {
     }
 }
 
-// TODO replace the references with `Cow` and bridge to the owned APIs for mutability
+impl<'val> BorrowedStruct<'val> {
+    /// Returns the struct's (field name, value) pairs sorted into a canonical order. Ion
+    /// structs are unordered multisets of fields, so sorting both sides the same way before
+    /// comparing them makes field order irrelevant while still requiring duplicate fields to
+    /// match in multiplicity.
+    fn canonical_fields(&self) -> Vec<(&BorrowedSymbolToken<'val>, &BorrowedElement<'val>)> {
+        let mut fields: Vec<_> = self
+            .fields
+            .iter()
+            .map(|(name, value)| (name, value))
+            .collect();
+        fields.sort_by(|(name_a, value_a), (name_b, value_b)| {
+            name_a.cmp(name_b).then_with(|| value_a.cmp(value_b))
+        });
+        fields
+    }
+}
+
+/// Structs compare as unordered multisets of (field name, value) pairs: field order doesn't
+/// matter, but duplicate fields must match in multiplicity.
+impl<'val> PartialEq for BorrowedStruct<'val> {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields.len() == other.fields.len()
+            && self.canonical_fields() == other.canonical_fields()
+    }
+}
+
+impl<'val> Eq for BorrowedStruct<'val> {}
+
+impl<'val> PartialOrd for BorrowedStruct<'val> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'val> Ord for BorrowedStruct<'val> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.canonical_fields().cmp(&other.canonical_fields())
+    }
+}
+
+// TODO replace the `&'val str`/`&'val [u8]` references with `Cow` so a single type can hold
+//      either borrowed or owned data and the owned/borrowed split collapses into one
+//      representation. The bridge to the owned APIs (`into_owned`/`to_owned`, below) is done.
 
 /// Variants for all borrowed version _values_ within an [`Element`].
 #[derive(Debug, Clone)]
 pub enum BorrowedValue<'val> {
     Null(IonType),
     Integer(AnyInt),
+    Float(f64),
+    Decimal(Decimal),
+    Timestamp(Timestamp),
     String(&'val str),
     Symbol(BorrowedSymbolToken<'val>),
+    Boolean(bool),
+    Blob(&'val [u8]),
+    Clob(&'val [u8]),
     SExpression(BorrowedSequence<'val>),
     List(BorrowedSequence<'val>),
     Struct(BorrowedStruct<'val>),
-    // TODO fill this in with the rest...
+}
+
+impl<'val> BorrowedValue<'val> {
+    /// Constructs an [`OwnedValue`] from this, detaching it from the `'val` borrow.
+    pub fn into_owned(self) -> OwnedValue {
+        use BorrowedValue::*;
+        match self {
+            Null(t) => OwnedValue::Null(t),
+            Integer(i) => OwnedValue::Integer(i),
+            Float(f) => OwnedValue::Float(f),
+            Decimal(d) => OwnedValue::Decimal(d),
+            Timestamp(t) => OwnedValue::Timestamp(t),
+            String(text) => OwnedValue::String(text.to_string()),
+            Symbol(sym) => OwnedValue::Symbol(sym.into_owned()),
+            Boolean(b) => OwnedValue::Boolean(b),
+            Blob(bytes) => OwnedValue::Blob(bytes.to_vec()),
+            Clob(bytes) => OwnedValue::Clob(bytes.to_vec()),
+            SExpression(seq) => OwnedValue::SExpression(seq.into_owned()),
+            List(seq) => OwnedValue::List(seq.into_owned()),
+            Struct(structure) => OwnedValue::Struct(structure.into_owned()),
+        }
+    }
+
+    /// Constructs an [`OwnedValue`] that owns a copy of this reference's data.
+    pub fn to_owned(&self) -> OwnedValue {
+        use BorrowedValue::*;
+        match self {
+            Null(t) => OwnedValue::Null(*t),
+            Integer(i) => OwnedValue::Integer(i.clone()),
+            Float(f) => OwnedValue::Float(*f),
+            Decimal(d) => OwnedValue::Decimal(d.clone()),
+            Timestamp(t) => OwnedValue::Timestamp(t.clone()),
+            String(text) => OwnedValue::String(text.to_string()),
+            Symbol(sym) => OwnedValue::Symbol(sym.to_owned()),
+            Boolean(b) => OwnedValue::Boolean(*b),
+            Blob(bytes) => OwnedValue::Blob(bytes.to_vec()),
+            Clob(bytes) => OwnedValue::Clob(bytes.to_vec()),
+            SExpression(seq) => OwnedValue::SExpression(seq.to_owned()),
+            List(seq) => OwnedValue::List(seq.to_owned()),
+            Struct(structure) => OwnedValue::Struct(structure.to_owned()),
+        }
+    }
+
+    /// This value's position in the canonical ordering used by [`Ord`], following the order the
+    /// variants are declared in above. Values of different Ion types are never equal, so their
+    /// relative order here is arbitrary but total.
+    fn variant_rank(&self) -> u8 {
+        use BorrowedValue::*;
+        match self {
+            Null(_) => 0,
+            Integer(_) => 1,
+            Float(_) => 2,
+            Decimal(_) => 3,
+            Timestamp(_) => 4,
+            String(_) => 5,
+            Symbol(_) => 6,
+            Boolean(_) => 7,
+            Blob(_) => 8,
+            Clob(_) => 9,
+            SExpression(_) => 10,
+            List(_) => 11,
+            Struct(_) => 12,
+        }
+    }
+}
+
+/// Compares two values that only have a partial order (like [`AnyInt`], [`Decimal`], or
+/// [`Timestamp`] may), treating incomparable values as equal rather than panicking. Used to give
+/// [`BorrowedValue`] a total [`Ord`] even though some of its variants wrap `PartialOrd`-only
+/// types.
+///
+/// This is *not* used for `f64`: `partial_cmp` returns `None` whenever *either* operand is `NaN`,
+/// not just when both are, so falling back to `Equal` here would make `NaN` compare equal to
+/// every other float (not just itself), which breaks transitivity. `f64::total_cmp` is used for
+/// `Float` instead, since it defines a real total order where only NaN-vs-NaN is `Equal`.
+fn total_cmp<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+/// Ion data-equivalence: values are equal only when they share the same Ion type and are equal
+/// within it (no cross-type coercion, e.g. `1` and `1.0` are never equal). See the match arms
+/// in `eq` below for how each variant's payload is compared.
+///
+/// `Float` is compared with `f64::total_cmp` rather than `f64`'s `==` so that `eq` agrees with
+/// `Ord::cmp` below (in particular, `NaN` is equal to itself under both, and not to anything
+/// else) — otherwise `Eq` would not be reflexive, which `HashSet`/`BTreeSet`/dedup consumers
+/// rely on.
+impl<'val> PartialEq for BorrowedValue<'val> {
+    fn eq(&self, other: &Self) -> bool {
+        use BorrowedValue::*;
+        match (self, other) {
+            (Null(a), Null(b)) => a == b,
+            (Integer(a), Integer(b)) => a == b,
+            (Float(a), Float(b)) => a.total_cmp(b) == Ordering::Equal,
+            (Decimal(a), Decimal(b)) => a == b,
+            (Timestamp(a), Timestamp(b)) => a == b,
+            (String(a), String(b)) => a == b,
+            (Symbol(a), Symbol(b)) => a == b,
+            (Boolean(a), Boolean(b)) => a == b,
+            (Blob(a), Blob(b)) => a == b,
+            (Clob(a), Clob(b)) => a == b,
+            (SExpression(a), SExpression(b)) => a == b,
+            (List(a), List(b)) => a == b,
+            (Struct(a), Struct(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<'val> Eq for BorrowedValue<'val> {}
+
+impl<'val> PartialOrd for BorrowedValue<'val> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'val> Ord for BorrowedValue<'val> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use BorrowedValue::*;
+        match (self, other) {
+            (Null(a), Null(b)) => (*a as u8).cmp(&(*b as u8)),
+            (Integer(a), Integer(b)) => total_cmp(a, b),
+            (Float(a), Float(b)) => a.total_cmp(b),
+            (Decimal(a), Decimal(b)) => total_cmp(a, b),
+            (Timestamp(a), Timestamp(b)) => total_cmp(a, b),
+            (String(a), String(b)) => a.cmp(b),
+            (Symbol(a), Symbol(b)) => a.cmp(b),
+            (Boolean(a), Boolean(b)) => a.cmp(b),
+            (Blob(a), Blob(b)) => a.cmp(b),
+            (Clob(a), Clob(b)) => a.cmp(b),
+            (SExpression(a), SExpression(b)) => a.cmp(b),
+            (List(a), List(b)) => a.cmp(b),
+            (Struct(a), Struct(b)) => a.cmp(b),
+            _ => self.variant_rank().cmp(&other.variant_rank()),
+        }
+    }
 }
 
 /// A borrowed implementation of [`Element`]
@@ -153,6 +483,29 @@ impl<'val> BorrowedElement<'val> {
     pub fn new(annotations: Vec<BorrowedSymbolToken<'val>>, value: BorrowedValue<'val>) -> Self {
         Self { annotations, value }
     }
+
+    /// Constructs an [`OwnedElement`] from this, detaching it from the `'val` borrow. This
+    /// allows the result of a zero-copy parse to outlive the buffer it was parsed from.
+    pub fn into_owned(self) -> OwnedElement {
+        OwnedElement::new(
+            self.annotations
+                .into_iter()
+                .map(BorrowedSymbolToken::into_owned)
+                .collect(),
+            self.value.into_owned(),
+        )
+    }
+
+    /// Constructs an [`OwnedElement`] that owns a copy of this reference's data.
+    pub fn to_owned(&self) -> OwnedElement {
+        OwnedElement::new(
+            self.annotations
+                .iter()
+                .map(BorrowedSymbolToken::to_owned)
+                .collect(),
+            self.value.to_owned(),
+        )
+    }
 }
 
 impl<'val> From<BorrowedValue<'val>> for BorrowedElement<'val> {
@@ -162,6 +515,29 @@ impl<'val> From<BorrowedValue<'val>> for BorrowedElement<'val> {
     }
 }
 
+/// Elements are equal when their annotations (compared in order) and their values are equal.
+impl<'val> PartialEq for BorrowedElement<'val> {
+    fn eq(&self, other: &Self) -> bool {
+        self.annotations == other.annotations && self.value == other.value
+    }
+}
+
+impl<'val> Eq for BorrowedElement<'val> {}
+
+impl<'val> PartialOrd for BorrowedElement<'val> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'val> Ord for BorrowedElement<'val> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value
+            .cmp(&other.value)
+            .then_with(|| self.annotations.cmp(&other.annotations))
+    }
+}
+
 impl<'val> Element for BorrowedElement<'val> {
     type SymbolToken = BorrowedSymbolToken<'val>;
     type Sequence = BorrowedSequence<'val>;
@@ -172,8 +548,14 @@ impl<'val> Element for BorrowedElement<'val> {
         match &self.value {
             Null(t) => *t,
             Integer(_) => IonType::Integer,
+            Float(_) => IonType::Float,
+            Decimal(_) => IonType::Decimal,
+            Timestamp(_) => IonType::Timestamp,
             String(_) => IonType::String,
             Symbol(_) => IonType::Symbol,
+            Boolean(_) => IonType::Boolean,
+            Blob(_) => IonType::Blob,
+            Clob(_) => IonType::Clob,
             SExpression(_) => IonType::SExpression,
             List(_) => IonType::List,
             Struct(_) => IonType::Struct,
@@ -198,6 +580,27 @@ impl<'val> Element for BorrowedElement<'val> {
         }
     }
 
+    fn as_f64(&self) -> Option<f64> {
+        match &self.value {
+            BorrowedValue::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    fn as_decimal(&self) -> Option<&Decimal> {
+        match &self.value {
+            BorrowedValue::Decimal(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    fn as_timestamp(&self) -> Option<&Timestamp> {
+        match &self.value {
+            BorrowedValue::Timestamp(t) => Some(t),
+            _ => None,
+        }
+    }
+
     fn as_str(&self) -> Option<&str> {
         match &self.value {
             BorrowedValue::String(text) => Some(*text),
@@ -213,6 +616,20 @@ impl<'val> Element for BorrowedElement<'val> {
         }
     }
 
+    fn as_bool(&self) -> Option<bool> {
+        match &self.value {
+            BorrowedValue::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    fn as_bytes(&self) -> Option<&[u8]> {
+        match &self.value {
+            BorrowedValue::Blob(bytes) | BorrowedValue::Clob(bytes) => Some(*bytes),
+            _ => None,
+        }
+    }
+
     fn as_sequence(&self) -> Option<&Self::Sequence> {
         match &self.value {
             BorrowedValue::SExpression(seq) | BorrowedValue::List(seq) => Some(seq),
@@ -226,4 +643,108 @@ impl<'val> Element for BorrowedElement<'val> {
             _ => None,
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_field<'val>(
+        name: BorrowedSymbolToken<'val>,
+        value: i64,
+    ) -> (BorrowedSymbolToken<'val>, BorrowedElement<'val>) {
+        (name, BorrowedValue::Integer(AnyInt::I64(value)).into())
+    }
+
+    #[test]
+    fn symbols_are_equal_by_resolved_text_regardless_of_sid_or_source() {
+        let by_text = BorrowedSymbolToken::new(Some("foo"), None, None);
+        let by_sid = BorrowedSymbolToken::new(Some("foo"), Some(10), None);
+        let with_source = BorrowedSymbolToken::new(
+            Some("foo"),
+            Some(10),
+            Some(BorrowedImportSource::new("shared", 10)),
+        );
+        assert_eq!(by_text, by_sid);
+        assert_eq!(by_text, with_source);
+
+        let other_text = BorrowedSymbolToken::new(Some("bar"), Some(10), None);
+        assert_ne!(by_text, other_text);
+    }
+
+    #[test]
+    fn structs_are_equal_regardless_of_field_order() {
+        let a = BorrowedStruct::new(vec![
+            int_field("x".into(), 1),
+            int_field("y".into(), 2),
+        ]);
+        let b = BorrowedStruct::new(vec![
+            int_field("y".into(), 2),
+            int_field("x".into(), 1),
+        ]);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn structs_require_duplicate_fields_to_match_in_multiplicity() {
+        let one_x = BorrowedStruct::new(vec![int_field("x".into(), 1)]);
+        let two_x = BorrowedStruct::new(vec![
+            int_field("x".into(), 1),
+            int_field("x".into(), 1),
+        ]);
+        assert_ne!(one_x, two_x);
+    }
+
+    #[test]
+    fn sequences_compare_element_wise_in_order() {
+        let ascending = BorrowedSequence::new(vec![
+            BorrowedValue::Integer(AnyInt::I64(1)).into(),
+            BorrowedValue::Integer(AnyInt::I64(2)).into(),
+        ]);
+        let descending = BorrowedSequence::new(vec![
+            BorrowedValue::Integer(AnyInt::I64(2)).into(),
+            BorrowedValue::Integer(AnyInt::I64(1)).into(),
+        ]);
+        assert_ne!(ascending, descending);
+        assert_eq!(
+            ascending,
+            BorrowedSequence::new(vec![
+                BorrowedValue::Integer(AnyInt::I64(1)).into(),
+                BorrowedValue::Integer(AnyInt::I64(2)).into(),
+            ])
+        );
+    }
+
+    #[test]
+    fn nulls_of_different_ion_types_are_not_equal() {
+        assert_ne!(
+            BorrowedValue::Null(IonType::String),
+            BorrowedValue::Null(IonType::Integer)
+        );
+        assert_eq!(
+            BorrowedValue::Null(IonType::String),
+            BorrowedValue::Null(IonType::String)
+        );
+    }
+
+    #[test]
+    fn nan_float_is_reflexively_equal_and_agrees_with_ord() {
+        let nan = BorrowedValue::Float(f64::NAN);
+        assert_eq!(nan, nan.clone());
+        assert_eq!(nan.cmp(&nan.clone()), Ordering::Equal);
+    }
+
+    #[test]
+    fn nan_float_is_not_equal_to_finite_floats() {
+        let nan = BorrowedValue::Float(f64::NAN);
+        let five = BorrowedValue::Float(5.0);
+        let seven = BorrowedValue::Float(7.0);
+
+        assert_ne!(nan, five);
+        assert_ne!(nan, seven);
+        assert_ne!(nan.cmp(&five), Ordering::Equal);
+        assert_ne!(nan.cmp(&seven), Ordering::Equal);
+        // Equality must stay transitive: NaN isn't "equal" to two floats that differ.
+        assert_ne!(five, seven);
+    }
 }
\ No newline at end of file
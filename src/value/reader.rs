@@ -0,0 +1,490 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! A zero-copy reader over binary Ion, producing [`BorrowedElement`]s that borrow their
+//! string/symbol/lob payloads directly from the input buffer.
+//!
+//! This follows the design of the Preserves packed binary reader: the reader walks the input
+//! buffer and hands back values whose variable-length fields point back into that same buffer
+//! rather than being copied out of it. The fixed-size scalars (booleans, integers that fit
+//! in a machine word, floats) are decoded inline; string and lob contents are `&'val` slices of
+//! the input, and symbol text comes from the caller's [`LocalSymbolTable`]. Non-null decimals and
+//! timestamps are not decoded: reading one returns an error.
+
+use super::borrowed::{
+    BorrowedElement, BorrowedSequence, BorrowedStruct, BorrowedSymbolToken, BorrowedValue,
+};
+use crate::result::{decoding_error, IonResult};
+use crate::types::SymbolId;
+use crate::value::AnyInt;
+use crate::IonType;
+
+/// Maps local symbol IDs (as they appear on the wire) to their text, so that the reader can
+/// populate [`BorrowedSymbolToken::text`] without having to parse and retain its own local
+/// symbol table.
+///
+/// TODO: teach the reader to build this itself from an embedded `$ion_symbol_table` struct
+/// rather than requiring the caller to supply one up front.
+pub struct LocalSymbolTable<'val> {
+    symbols: &'val [Option<&'val str>],
+}
+
+impl<'val> LocalSymbolTable<'val> {
+    pub fn new(symbols: &'val [Option<&'val str>]) -> Self {
+        Self { symbols }
+    }
+
+    fn text_for(&self, local_sid: SymbolId) -> Option<&'val str> {
+        self.symbols.get(local_sid).copied().flatten()
+    }
+}
+
+/// A streaming, zero-copy reader over a single buffer of binary Ion, yielding one top-level
+/// [`BorrowedElement`] at a time via [`BinaryElementReader::next`].
+///
+/// The reader does not materialize the whole document at once, so callers can process buffers
+/// larger than they'd want to hold as a fully parsed tree.
+pub struct BinaryElementReader<'val> {
+    input: &'val [u8],
+    position: usize,
+    symbol_table: LocalSymbolTable<'val>,
+}
+
+impl<'val> BinaryElementReader<'val> {
+    pub fn new(input: &'val [u8], symbol_table: LocalSymbolTable<'val>) -> Self {
+        Self {
+            input,
+            position: 0,
+            symbol_table,
+        }
+    }
+
+    /// Reads the next top-level value, or `None` once the input is exhausted.
+    pub fn next(&mut self) -> Option<IonResult<BorrowedElement<'val>>> {
+        if self.position >= self.input.len() {
+            return None;
+        }
+        Some(self.read_element())
+    }
+
+    fn remaining(&self) -> &'val [u8] {
+        &self.input[self.position..]
+    }
+
+    fn take(&mut self, len: usize) -> IonResult<&'val [u8]> {
+        let remaining = self.remaining();
+        if remaining.len() < len {
+            return decoding_error("unexpected end of input while reading a value");
+        }
+        let (bytes, _) = remaining.split_at(len);
+        self.position += len;
+        Ok(bytes)
+    }
+
+    fn take_byte(&mut self) -> IonResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_var_uint(&mut self) -> IonResult<u64> {
+        let mut value: u64 = 0;
+        loop {
+            let byte = self.take_byte()?;
+            value = (value << 7) | (byte & 0x7F) as u64;
+            if byte & 0x80 != 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn read_length(&mut self, length_code: u8) -> IonResult<usize> {
+        match length_code {
+            14 => Ok(self.read_var_uint()? as usize),
+            15 => Ok(0),
+            len => Ok(len as usize),
+        }
+    }
+
+    /// Adds `length` to the reader's current position to get the offset a value's contents
+    /// end at, checking for overflow first. `length` can come straight from an untrusted VarUInt
+    /// (length code `14`), so without this check a corrupt or adversarial input could push
+    /// `position + length` past `usize::MAX` — panicking in a debug build (overflow checks are
+    /// on by default) or silently wrapping around in release.
+    fn checked_end(&self, length: usize) -> IonResult<usize> {
+        self.position
+            .checked_add(length)
+            .ok_or(())
+            .or_else(|_| decoding_error("value length overflows the reader's position"))
+    }
+
+    fn symbol_token_for(&self, sid: SymbolId) -> BorrowedSymbolToken<'val> {
+        BorrowedSymbolToken::new(self.symbol_table.text_for(sid), Some(sid), None)
+    }
+
+    /// Reads one complete top-level or nested value: its type descriptor, any annotations, and
+    /// its body.
+    fn read_element(&mut self) -> IonResult<BorrowedElement<'val>> {
+        let descriptor = self.take_byte()?;
+        let type_code = descriptor >> 4;
+        let length_code = descriptor & 0x0F;
+
+        if type_code == 14 {
+            let (annotations, value) = self.read_annotated_value(length_code)?;
+            return Ok(BorrowedElement::new(annotations, value));
+        }
+
+        let value = self.read_body(type_code, length_code)?;
+        Ok(BorrowedElement::from(value))
+    }
+
+    /// Reads the contents of an annotation wrapper: the list of annotation symbol IDs followed
+    /// by the single value they annotate. Binary Ion does not allow wrappers to nest.
+    fn read_annotated_value(
+        &mut self,
+        length_code: u8,
+    ) -> IonResult<(Vec<BorrowedSymbolToken<'val>>, BorrowedValue<'val>)> {
+        let wrapper_length = self.read_length(length_code)?;
+        let wrapper_end = self.checked_end(wrapper_length)?;
+
+        let annot_length = self.read_var_uint()? as usize;
+        let annot_end = self.checked_end(annot_length)?;
+        let mut annotations = vec![];
+        while self.position < annot_end {
+            let sid = self.read_var_uint()? as SymbolId;
+            annotations.push(self.symbol_token_for(sid));
+        }
+        if self.position != annot_end {
+            return decoding_error("annotation wrapper's annotation list length was wrong");
+        }
+
+        let descriptor = self.take_byte()?;
+        let type_code = descriptor >> 4;
+        if type_code == 14 {
+            return decoding_error("annotation wrappers may not be nested");
+        }
+        let length_code = descriptor & 0x0F;
+        let value = self.read_body(type_code, length_code)?;
+
+        if self.position != wrapper_end {
+            return decoding_error("annotation wrapper length did not match its contents");
+        }
+        Ok((annotations, value))
+    }
+
+    /// Reads the body of a value (everything after the type descriptor) given its type code and
+    /// length code.
+    fn read_body(&mut self, type_code: u8, length_code: u8) -> IonResult<BorrowedValue<'val>> {
+        let is_null = length_code == 15;
+        match type_code {
+            0 => Ok(BorrowedValue::Null(IonType::Null)),
+            1 => self.read_bool(length_code, is_null),
+            2 => self.read_int(length_code, is_null, false),
+            3 => self.read_int(length_code, is_null, true),
+            4 => self.read_float(length_code, is_null),
+            5 => {
+                if is_null {
+                    Ok(BorrowedValue::Null(IonType::Decimal))
+                } else {
+                    decoding_error("decimal binary decoding is not yet implemented")
+                }
+            }
+            6 => {
+                if is_null {
+                    Ok(BorrowedValue::Null(IonType::Timestamp))
+                } else {
+                    decoding_error("timestamp binary decoding is not yet implemented")
+                }
+            }
+            7 => self.read_symbol(length_code, is_null),
+            8 => self.read_string(length_code, is_null),
+            9 => self.read_lob(length_code, is_null, BorrowedValue::Clob, IonType::Clob),
+            10 => self.read_lob(length_code, is_null, BorrowedValue::Blob, IonType::Blob),
+            11 => self.read_sequence(length_code, is_null, BorrowedValue::List, IonType::List),
+            12 => self.read_sequence(
+                length_code,
+                is_null,
+                BorrowedValue::SExpression,
+                IonType::SExpression,
+            ),
+            13 => self.read_struct(length_code, is_null),
+            _ => decoding_error(format!("reserved or unsupported type code {}", type_code)),
+        }
+    }
+
+    fn read_bool(&mut self, length_code: u8, is_null: bool) -> IonResult<BorrowedValue<'val>> {
+        if is_null {
+            return Ok(BorrowedValue::Null(IonType::Boolean));
+        }
+        match length_code {
+            0 => Ok(BorrowedValue::Boolean(false)),
+            1 => Ok(BorrowedValue::Boolean(true)),
+            _ => decoding_error("boolean representation must have length 0 or 1"),
+        }
+    }
+
+    fn read_int(
+        &mut self,
+        length_code: u8,
+        is_null: bool,
+        negative: bool,
+    ) -> IonResult<BorrowedValue<'val>> {
+        if is_null {
+            return Ok(BorrowedValue::Null(IonType::Integer));
+        }
+        let length = self.read_length(length_code)?;
+        let bytes = self.take(length)?;
+        if bytes.len() > 8 {
+            // TODO: fall back to `AnyInt::BigInt` for magnitudes that don't fit in an i64.
+            return decoding_error("integers wider than 8 bytes are not yet supported");
+        }
+        let mut magnitude: u64 = 0;
+        for byte in bytes {
+            magnitude = (magnitude << 8) | *byte as u64;
+        }
+        // `i64::MIN`'s magnitude (2^63) is a legal encoding but has no positive i64
+        // counterpart, and a non-negative magnitude that size or larger doesn't fit in an i64
+        // at all, so both must be handled before the cast below rather than after it.
+        const I64_MIN_MAGNITUDE: u64 = 1u64 << 63;
+        let value = if negative {
+            if magnitude == I64_MIN_MAGNITUDE {
+                i64::MIN
+            } else if magnitude < I64_MIN_MAGNITUDE {
+                -(magnitude as i64)
+            } else {
+                // TODO: fall back to `AnyInt::BigInt` for magnitudes that don't fit in an i64.
+                return decoding_error("negative integer magnitude does not fit in an i64");
+            }
+        } else if magnitude < I64_MIN_MAGNITUDE {
+            magnitude as i64
+        } else {
+            // TODO: fall back to `AnyInt::BigInt` for magnitudes that don't fit in an i64.
+            return decoding_error("integer magnitude does not fit in an i64");
+        };
+        Ok(BorrowedValue::Integer(AnyInt::I64(value)))
+    }
+
+    fn read_float(&mut self, length_code: u8, is_null: bool) -> IonResult<BorrowedValue<'val>> {
+        if is_null {
+            return Ok(BorrowedValue::Null(IonType::Float));
+        }
+        match length_code {
+            0 => Ok(BorrowedValue::Float(0.0)),
+            4 => {
+                let bytes = self.take(4)?;
+                let mut buf = [0u8; 4];
+                buf.copy_from_slice(bytes);
+                Ok(BorrowedValue::Float(f32::from_be_bytes(buf) as f64))
+            }
+            8 => {
+                let bytes = self.take(8)?;
+                let mut buf = [0u8; 8];
+                buf.copy_from_slice(bytes);
+                Ok(BorrowedValue::Float(f64::from_be_bytes(buf)))
+            }
+            _ => decoding_error("float representation must have length 0, 4, or 8"),
+        }
+    }
+
+    fn read_symbol(&mut self, length_code: u8, is_null: bool) -> IonResult<BorrowedValue<'val>> {
+        if is_null {
+            return Ok(BorrowedValue::Null(IonType::Symbol));
+        }
+        let length = self.read_length(length_code)?;
+        let bytes = self.take(length)?;
+        if bytes.len() > 8 {
+            return decoding_error("symbol ID representation wider than 8 bytes is not supported");
+        }
+        let mut sid: u64 = 0;
+        for byte in bytes {
+            sid = (sid << 8) | *byte as u64;
+        }
+        Ok(BorrowedValue::Symbol(self.symbol_token_for(sid as SymbolId)))
+    }
+
+    fn read_string(&mut self, length_code: u8, is_null: bool) -> IonResult<BorrowedValue<'val>> {
+        if is_null {
+            return Ok(BorrowedValue::Null(IonType::String));
+        }
+        let length = self.read_length(length_code)?;
+        let bytes = self.take(length)?;
+        let text = std::str::from_utf8(bytes)
+            .or_else(|_| decoding_error("string representation was not valid UTF-8"))?;
+        Ok(BorrowedValue::String(text))
+    }
+
+    fn read_lob(
+        &mut self,
+        length_code: u8,
+        is_null: bool,
+        ctor: fn(&'val [u8]) -> BorrowedValue<'val>,
+        null_type: IonType,
+    ) -> IonResult<BorrowedValue<'val>> {
+        if is_null {
+            return Ok(BorrowedValue::Null(null_type));
+        }
+        let length = self.read_length(length_code)?;
+        let bytes = self.take(length)?;
+        Ok(ctor(bytes))
+    }
+
+    fn read_sequence(
+        &mut self,
+        length_code: u8,
+        is_null: bool,
+        ctor: fn(BorrowedSequence<'val>) -> BorrowedValue<'val>,
+        null_type: IonType,
+    ) -> IonResult<BorrowedValue<'val>> {
+        if is_null {
+            return Ok(BorrowedValue::Null(null_type));
+        }
+        let length = self.read_length(length_code)?;
+        let end = self.checked_end(length)?;
+        let mut children = vec![];
+        while self.position < end {
+            children.push(self.read_element()?);
+        }
+        if self.position != end {
+            return decoding_error("sequence length did not match its contents");
+        }
+        Ok(ctor(BorrowedSequence::new(children)))
+    }
+
+    fn read_struct(&mut self, length_code: u8, is_null: bool) -> IonResult<BorrowedValue<'val>> {
+        if is_null {
+            return Ok(BorrowedValue::Null(IonType::Struct));
+        }
+        // Unlike every other container, length code 1 on a struct isn't the literal length 1 —
+        // it's a hint that the fields are sorted by symbol ID, with the actual length following
+        // as a VarUInt (the same encoding `read_length` uses for length code 14). We don't make
+        // use of the sortedness, but we still need to read the length the same way it was
+        // written or the field data after it will be misaligned.
+        let length = if length_code == 1 {
+            self.read_var_uint()? as usize
+        } else {
+            self.read_length(length_code)?
+        };
+        let end = self.checked_end(length)?;
+        let mut fields = vec![];
+        while self.position < end {
+            let sid = self.read_var_uint()? as SymbolId;
+            let field_name = self.symbol_token_for(sid);
+            let value = self.read_element()?;
+            fields.push((field_name, value));
+        }
+        if self.position != end {
+            return decoding_error("struct length did not match its contents");
+        }
+        Ok(BorrowedValue::Struct(BorrowedStruct::new(fields)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::{Element, SymbolToken};
+
+    fn read_one(input: &[u8]) -> BorrowedElement {
+        let symbols: &[Option<&str>] = &[];
+        let mut reader = BinaryElementReader::new(input, LocalSymbolTable::new(symbols));
+        reader.next().expect("expected a value").expect("expected Ok")
+    }
+
+    fn read_one_with_symbols<'val>(
+        input: &'val [u8],
+        symbols: &'val [Option<&'val str>],
+    ) -> BorrowedElement<'val> {
+        let mut reader = BinaryElementReader::new(input, LocalSymbolTable::new(symbols));
+        reader.next().expect("expected a value").expect("expected Ok")
+    }
+
+    #[test]
+    fn reads_booleans() {
+        assert_eq!(read_one(&[0x11]).as_bool(), Some(true));
+        assert_eq!(read_one(&[0x10]).as_bool(), Some(false));
+    }
+
+    #[test]
+    fn reads_positive_and_negative_ints() {
+        assert_eq!(read_one(&[0x21, 0x05]).as_any_int().unwrap().as_i64(), Some(5));
+        assert_eq!(read_one(&[0x31, 0x05]).as_any_int().unwrap().as_i64(), Some(-5));
+    }
+
+    #[test]
+    fn rejects_positive_int_magnitude_that_does_not_fit_in_i64() {
+        // Eight 0xFF bytes: a legal Ion encoding, but its magnitude is larger than i64::MAX.
+        let bytes = [0x28, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        let symbols: &[Option<&str>] = &[];
+        let mut reader = BinaryElementReader::new(&bytes, LocalSymbolTable::new(symbols));
+        assert!(reader.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn reads_negative_int_at_i64_min_without_panicking() {
+        // Magnitude 2^63, negative: the legal encoding of i64::MIN.
+        let bytes = [0x38, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        assert_eq!(read_one(&bytes).as_any_int().unwrap().as_i64(), Some(i64::MIN));
+    }
+
+    #[test]
+    fn reads_strings() {
+        let bytes = [0x82, b'h', b'i'];
+        assert_eq!(read_one(&bytes).as_str(), Some("hi"));
+    }
+
+    #[test]
+    fn reads_symbols_via_the_local_symbol_table() {
+        let symbols: &[Option<&str>] = &[None, Some("foo")];
+        let bytes = [0x71, 0x01];
+        let element = read_one_with_symbols(&bytes, symbols);
+        assert_eq!(element.as_sym().unwrap().text(), Some("foo"));
+    }
+
+    #[test]
+    fn reads_lists_of_scalars() {
+        // A two-element list: the ints 1 and 2.
+        let bytes = [0xB4, 0x21, 0x01, 0x21, 0x02];
+        let element = read_one(&bytes);
+        let sequence = element.as_sequence().unwrap();
+        let values: Vec<_> = sequence
+            .iter()
+            .map(|e| e.as_any_int().unwrap().as_i64())
+            .collect();
+        assert_eq!(values, vec![Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn reads_structs_with_one_field() {
+        // { 1: 5 } using local symbol ID 1 for the field name.
+        let symbols: &[Option<&str>] = &[None, Some("a")];
+        let bytes = [0xD3, 0x81, 0x21, 0x05];
+        let element = read_one_with_symbols(&bytes, symbols);
+        let structure = element.as_struct().unwrap();
+        assert_eq!(
+            structure.get("a").unwrap().as_any_int().unwrap().as_i64(),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn reads_sorted_structs_with_length_code_one() {
+        // { 1: 5 }, but written with the "fields sorted by symbol ID" hint (length code 1),
+        // whose actual length (3) follows as a VarUInt rather than being the code itself.
+        let symbols: &[Option<&str>] = &[None, Some("a")];
+        let bytes = [0xD1, 0x83, 0x81, 0x21, 0x05];
+        let element = read_one_with_symbols(&bytes, symbols);
+        let structure = element.as_struct().unwrap();
+        assert_eq!(
+            structure.get("a").unwrap().as_any_int().unwrap().as_i64(),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn reads_annotation_wrappers() {
+        // An int 5 annotated with local symbol ID 1.
+        let symbols: &[Option<&str>] = &[None, Some("ann")];
+        let bytes = [0xE4, 0x81, 0x81, 0x21, 0x05];
+        let element = read_one_with_symbols(&bytes, symbols);
+        let annotations: Vec<_> = element.annotations().map(|a| a.text()).collect();
+        assert_eq!(annotations, vec![Some("ann")]);
+        assert_eq!(element.as_any_int().unwrap().as_i64(), Some(5));
+    }
+}